@@ -1,24 +1,65 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::io::{Read, Write};
+
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
     UnexpectedEof,
     FromUtf8Error,
     ParseIntError,
+    ParseFloatError,
     UnexpectedByte(u8),
     UnforeseenError,
 }
 
+/// Errors surfaced by the high-level [`Connection`] and typed-conversion layers.
+///
+/// Wire-parsing failures arrive as [`RespError::Parse`], transport failures as
+/// [`RespError::Io`], and `-ERR ...` replies folded by the reply helpers as
+/// [`RespError::Server`].
+#[derive(Debug)]
+pub enum RespError {
+    Io(std::io::Error),
+    Parse(ParseError),
+    Server(String),
+    UnexpectedType,
+    UnexpectedEof,
+}
+
+impl From<std::io::Error> for RespError {
+    fn from(error: std::io::Error) -> Self {
+        RespError::Io(error)
+    }
+}
+
+impl From<ParseError> for RespError {
+    fn from(error: ParseError) -> Self {
+        RespError::Parse(error)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum RespType {
     SimpleString(String),
     Error(String),
     Integer(i64),
-    BulkString(Option<Vec<u8>>),
+    BulkString(Option<Bytes>),
     Array(Vec<RespType>),
+    // RESP3 additions. These are only produced by servers that agreed to
+    // protocol version 3 via `HELLO 3`; a RESP2 server never emits them.
+    Null,
+    Boolean(bool),
+    Double(f64),
+    BigNumber(String),
+    BulkError(String),
+    VerbatimString { format: String, data: Bytes },
+    Map(Vec<(RespType, RespType)>),
+    Set(Vec<RespType>),
+    Push(Vec<RespType>),
 }
 
 impl RespType {
     pub fn from_bytes(bytes: &[u8]) -> Result<(&[u8], RespType), ParseError> {
-        match bytes.get(0) {
+        match bytes.first() {
             Some(b'+') => Self::read_string(&bytes[1..]),
             Some(b'-') => {
                 // Here we simply call read_string and then convert the SimpleString to an Error
@@ -42,7 +83,50 @@ impl RespType {
                 }
             }
             Some(b'$') => Self::read_bulk_string(&bytes[1..]),
-            Some(b'*') => Self::read_array(&bytes[1..]),
+            Some(b'*') => {
+                let (bytes, items) = Self::read_aggregate(&bytes[1..], 1)?;
+                Ok((bytes, RespType::Array(items)))
+            }
+            Some(b'_') => {
+                // A RESP3 null is just `_\r\n`; consume the empty line.
+                let (bytes, _) = Self::read_line(&bytes[1..])?;
+                Ok((bytes, RespType::Null))
+            }
+            Some(b'#') => {
+                let (bytes, line) = Self::read_line(&bytes[1..])?;
+                match line.as_slice() {
+                    b"t" => Ok((bytes, RespType::Boolean(true))),
+                    b"f" => Ok((bytes, RespType::Boolean(false))),
+                    _ => Err(ParseError::UnforeseenError),
+                }
+            }
+            Some(b',') => {
+                let (bytes, line) = Self::read_line(&bytes[1..])?;
+                let line = String::from_utf8(line).map_err(|_| ParseError::FromUtf8Error)?;
+                let double = match line.as_str() {
+                    "inf" => f64::INFINITY,
+                    "-inf" => f64::NEG_INFINITY,
+                    "nan" => f64::NAN,
+                    _ => line.parse::<f64>().map_err(|_| ParseError::ParseFloatError)?,
+                };
+                Ok((bytes, RespType::Double(double)))
+            }
+            Some(b'(') => {
+                let (bytes, line) = Self::read_line(&bytes[1..])?;
+                let line = String::from_utf8(line).map_err(|_| ParseError::FromUtf8Error)?;
+                Ok((bytes, RespType::BigNumber(line)))
+            }
+            Some(b'!') => Self::read_bulk_error(&bytes[1..]),
+            Some(b'=') => Self::read_verbatim_string(&bytes[1..]),
+            Some(b'%') => Self::read_map(&bytes[1..]),
+            Some(b'~') => {
+                let (bytes, items) = Self::read_aggregate(&bytes[1..], 1)?;
+                Ok((bytes, RespType::Set(items)))
+            }
+            Some(b'>') => {
+                let (bytes, items) = Self::read_aggregate(&bytes[1..], 1)?;
+                Ok((bytes, RespType::Push(items)))
+            }
             Some(byte) => Err(ParseError::UnexpectedByte(*byte))?,
             None => Err(ParseError::UnexpectedEof)?,
         }
@@ -59,23 +143,364 @@ impl RespType {
                         .chars()
                         .map(|c| c as u8)
                         .collect::<Vec<u8>>();
-                    bytes.extend(bulk);
+                    bytes.extend_from_slice(bulk);
                     bytes.extend(b"\r\n");
                     bytes
                 } else {
                     b"$-1\r\n".to_vec()
                 }
             }
-            RespType::Array(array) => {
-                let mut bytes = format!("*{}\r\n", array.len())
-                    .chars()
-                    .map(|c| c as u8)
-                    .collect::<Vec<u8>>();
-                for item in array {
-                    bytes.extend(item.as_bytes());
+            RespType::Array(array) => Self::aggregate_bytes(b'*', array),
+            RespType::Null => b"_\r\n".to_vec(),
+            RespType::Boolean(value) => {
+                if *value {
+                    b"#t\r\n".to_vec()
+                } else {
+                    b"#f\r\n".to_vec()
+                }
+            }
+            RespType::Double(double) => {
+                let value = if double.is_nan() {
+                    "nan".to_string()
+                } else if double.is_infinite() {
+                    if double.is_sign_positive() {
+                        "inf".to_string()
+                    } else {
+                        "-inf".to_string()
+                    }
+                } else {
+                    double.to_string()
+                };
+                format!(",{}\r\n", value).into_bytes()
+            }
+            RespType::BigNumber(number) => format!("({}\r\n", number).into_bytes(),
+            RespType::BulkError(error) => {
+                let mut bytes = format!("!{}\r\n", error.len()).into_bytes();
+                bytes.extend(error.as_bytes());
+                bytes.extend(b"\r\n");
+                bytes
+            }
+            RespType::VerbatimString { format, data } => {
+                // The wire length covers the 3-char format, the ':' separator and the data.
+                let mut bytes = format!("={}\r\n", format.len() + 1 + data.len()).into_bytes();
+                bytes.extend(format.as_bytes());
+                bytes.push(b':');
+                bytes.extend_from_slice(data);
+                bytes.extend(b"\r\n");
+                bytes
+            }
+            RespType::Map(map) => {
+                let mut bytes = format!("%{}\r\n", map.len()).into_bytes();
+                for (key, value) in map {
+                    bytes.extend(key.as_bytes());
+                    bytes.extend(value.as_bytes());
                 }
                 bytes
             }
+            RespType::Set(set) => Self::aggregate_bytes(b'~', set),
+            RespType::Push(push) => Self::aggregate_bytes(b'>', push),
+        }
+    }
+
+    /// Iterate over every top-level frame packed back-to-back in `bytes`.
+    ///
+    /// Redis pipelining returns many replies in a single socket read; this
+    /// threads the `remaining` slice that [`RespType::from_bytes`] already hands
+    /// back so each step yields the next frame, stopping cleanly once the buffer
+    /// is drained. A malformed frame is yielded as `Err` and ends iteration.
+    pub fn iter_frames(bytes: &[u8]) -> FrameIter<'_> {
+        FrameIter { remaining: bytes }
+    }
+
+    /// Interpret the frame as UTF-8 text.
+    ///
+    /// Accepts simple, bulk and verbatim strings; an `-ERR ...` reply is folded
+    /// into [`RespError::Server`] and any other shape is a [`RespError::UnexpectedType`].
+    pub fn as_str(&self) -> Result<&str, RespError> {
+        self.check_error()?;
+        match self {
+            RespType::SimpleString(string) => Ok(string),
+            RespType::BulkString(Some(bulk)) => {
+                std::str::from_utf8(bulk).map_err(|_| RespError::Parse(ParseError::FromUtf8Error))
+            }
+            RespType::VerbatimString { data, .. } => {
+                std::str::from_utf8(data).map_err(|_| RespError::Parse(ParseError::FromUtf8Error))
+            }
+            _ => Err(RespError::UnexpectedType),
+        }
+    }
+
+    /// Interpret the frame as a 64-bit integer, parsing a bulk string if needed.
+    pub fn as_i64(&self) -> Result<i64, RespError> {
+        self.check_error()?;
+        match self {
+            RespType::Integer(integer) => Ok(*integer),
+            RespType::BulkString(Some(bulk)) => std::str::from_utf8(bulk)
+                .ok()
+                .and_then(|string| string.parse::<i64>().ok())
+                .ok_or(RespError::UnexpectedType),
+            _ => Err(RespError::UnexpectedType),
+        }
+    }
+
+    /// Borrow the bulk payload, mapping a null bulk string (and RESP3 null) to `None`.
+    pub fn as_bytes_opt(&self) -> Result<Option<&[u8]>, RespError> {
+        self.check_error()?;
+        match self {
+            RespType::BulkString(Some(bulk)) => Ok(Some(bulk)),
+            RespType::BulkString(None) | RespType::Null => Ok(None),
+            _ => Err(RespError::UnexpectedType),
+        }
+    }
+
+    /// Consume the frame as the elements of an array, set or push.
+    pub fn into_vec(self) -> Result<Vec<RespType>, RespError> {
+        self.check_error()?;
+        match self {
+            RespType::Array(items) | RespType::Set(items) | RespType::Push(items) => Ok(items),
+            _ => Err(RespError::UnexpectedType),
+        }
+    }
+
+    fn check_error(&self) -> Result<(), RespError> {
+        match self {
+            RespType::Error(message) | RespType::BulkError(message) => {
+                Err(RespError::Server(message.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn aggregate_bytes(prefix: u8, items: &[RespType]) -> Vec<u8> {
+        let mut bytes = format!("{}{}\r\n", prefix as char, items.len()).into_bytes();
+        for item in items {
+            bytes.extend(item.as_bytes());
+        }
+        bytes
+    }
+
+    /// Parse a single frame out of `src`, advancing it past the consumed bytes.
+    ///
+    /// Unlike [`RespType::from_bytes`], bulk payloads are handed back as
+    /// [`Bytes`] slices that share `src`'s underlying allocation, so a large
+    /// bulk string is never copied out of the read buffer.
+    pub fn from_buf(src: &mut Bytes) -> Result<RespType, ParseError> {
+        match src.first() {
+            Some(b'+') => {
+                src.advance(1);
+                Ok(RespType::SimpleString(Self::read_string_buf(src)?))
+            }
+            Some(b'-') => {
+                src.advance(1);
+                Ok(RespType::Error(Self::read_string_buf(src)?))
+            }
+            Some(b':') => {
+                src.advance(1);
+                let integer = Self::read_string_buf(src)?
+                    .parse::<i64>()
+                    .map_err(|_| ParseError::ParseIntError)?;
+                Ok(RespType::Integer(integer))
+            }
+            Some(b'$') => {
+                src.advance(1);
+                Self::read_bulk_string_buf(src)
+            }
+            Some(b'*') => {
+                src.advance(1);
+                Ok(RespType::Array(Self::read_aggregate_buf(src, 1)?))
+            }
+            Some(b'_') => {
+                src.advance(1);
+                Self::read_line_buf(src)?;
+                Ok(RespType::Null)
+            }
+            Some(b'#') => {
+                src.advance(1);
+                match Self::read_line_buf(src)?.as_ref() {
+                    b"t" => Ok(RespType::Boolean(true)),
+                    b"f" => Ok(RespType::Boolean(false)),
+                    _ => Err(ParseError::UnforeseenError),
+                }
+            }
+            Some(b',') => {
+                src.advance(1);
+                let line = Self::read_string_buf(src)?;
+                let double = match line.as_str() {
+                    "inf" => f64::INFINITY,
+                    "-inf" => f64::NEG_INFINITY,
+                    "nan" => f64::NAN,
+                    _ => line.parse::<f64>().map_err(|_| ParseError::ParseFloatError)?,
+                };
+                Ok(RespType::Double(double))
+            }
+            Some(b'(') => {
+                src.advance(1);
+                Ok(RespType::BigNumber(Self::read_string_buf(src)?))
+            }
+            Some(b'!') => {
+                src.advance(1);
+                let data = Self::read_bulk_payload_buf(src)?;
+                let error = String::from_utf8(data.to_vec())
+                    .map_err(|_| ParseError::FromUtf8Error)?;
+                Ok(RespType::BulkError(error))
+            }
+            Some(b'=') => {
+                src.advance(1);
+                let payload = Self::read_bulk_payload_buf(src)?;
+                if payload.len() < 4 || payload[3] != b':' {
+                    return Err(ParseError::UnforeseenError);
+                }
+                let format = String::from_utf8(payload[..3].to_vec())
+                    .map_err(|_| ParseError::FromUtf8Error)?;
+                let data = payload.slice(4..);
+                Ok(RespType::VerbatimString { format, data })
+            }
+            Some(b'%') => {
+                src.advance(1);
+                let items = Self::read_aggregate_buf(src, 2)?;
+                let mut map: Vec<(RespType, RespType)> = Vec::with_capacity(items.len() / 2);
+                let mut items = items.into_iter();
+                while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                    map.push((key, value));
+                }
+                Ok(RespType::Map(map))
+            }
+            Some(b'~') => {
+                src.advance(1);
+                Ok(RespType::Set(Self::read_aggregate_buf(src, 1)?))
+            }
+            Some(b'>') => {
+                src.advance(1);
+                Ok(RespType::Push(Self::read_aggregate_buf(src, 1)?))
+            }
+            Some(byte) => Err(ParseError::UnexpectedByte(*byte)),
+            None => Err(ParseError::UnexpectedEof),
+        }
+    }
+
+    /// Encode `self` directly into a caller-provided [`BufMut`], avoiding the
+    /// per-frame `Vec` that [`RespType::as_bytes`] allocates.
+    pub fn encode<B: BufMut>(&self, dst: &mut B) {
+        match self {
+            RespType::SimpleString(string) => {
+                dst.put_u8(b'+');
+                dst.put_slice(string.as_bytes());
+                dst.put_slice(b"\r\n");
+            }
+            RespType::Error(error) => {
+                dst.put_u8(b'-');
+                dst.put_slice(error.as_bytes());
+                dst.put_slice(b"\r\n");
+            }
+            RespType::Integer(integer) => dst.put_slice(format!(":{}\r\n", integer).as_bytes()),
+            RespType::BulkString(bulk) => {
+                if let Some(bulk) = bulk {
+                    dst.put_slice(format!("${}\r\n", bulk.len()).as_bytes());
+                    dst.put_slice(bulk);
+                    dst.put_slice(b"\r\n");
+                } else {
+                    dst.put_slice(b"$-1\r\n");
+                }
+            }
+            RespType::Array(array) => Self::encode_aggregate(b'*', array, dst),
+            RespType::Null => dst.put_slice(b"_\r\n"),
+            RespType::Boolean(value) => {
+                dst.put_slice(if *value { b"#t\r\n" } else { b"#f\r\n" })
+            }
+            RespType::Double(_) | RespType::BigNumber(_) | RespType::BulkError(_) => {
+                // These carry no borrowed payload, so reuse the owned encoder.
+                dst.put_slice(&self.as_bytes())
+            }
+            RespType::VerbatimString { format, data } => {
+                dst.put_slice(format!("={}\r\n", format.len() + 1 + data.len()).as_bytes());
+                dst.put_slice(format.as_bytes());
+                dst.put_u8(b':');
+                dst.put_slice(data);
+                dst.put_slice(b"\r\n");
+            }
+            RespType::Map(map) => {
+                dst.put_slice(format!("%{}\r\n", map.len()).as_bytes());
+                for (key, value) in map {
+                    key.encode(dst);
+                    value.encode(dst);
+                }
+            }
+            RespType::Set(set) => Self::encode_aggregate(b'~', set, dst),
+            RespType::Push(push) => Self::encode_aggregate(b'>', push, dst),
+        }
+    }
+
+    fn encode_aggregate<B: BufMut>(prefix: u8, items: &[RespType], dst: &mut B) {
+        dst.put_slice(format!("{}{}\r\n", prefix as char, items.len()).as_bytes());
+        for item in items {
+            item.encode(dst);
+        }
+    }
+
+    fn read_string_buf(src: &mut Bytes) -> Result<String, ParseError> {
+        let line = Self::read_line_buf(src)?;
+        String::from_utf8(line.to_vec()).map_err(|_| ParseError::FromUtf8Error)
+    }
+
+    fn read_bulk_string_buf(src: &mut Bytes) -> Result<RespType, ParseError> {
+        let size = Self::read_string_buf(src)?
+            .parse::<i64>()
+            .map_err(|_| ParseError::ParseIntError)?;
+
+        if size == -1 {
+            return Ok(RespType::BulkString(None));
+        }
+
+        let size = size as usize;
+        if src.len() < size + 2 {
+            return Err(ParseError::UnexpectedEof);
+        }
+
+        let data = src.split_to(size);
+        src.advance(2);
+        Ok(RespType::BulkString(Some(data)))
+    }
+
+    fn read_bulk_payload_buf(src: &mut Bytes) -> Result<Bytes, ParseError> {
+        let size = Self::read_string_buf(src)?
+            .parse::<usize>()
+            .map_err(|_| ParseError::ParseIntError)?;
+
+        if src.len() < size + 2 {
+            return Err(ParseError::UnexpectedEof);
+        }
+
+        let data = src.split_to(size);
+        src.advance(2);
+        Ok(data)
+    }
+
+    fn read_aggregate_buf(src: &mut Bytes, multiplier: usize) -> Result<Vec<RespType>, ParseError> {
+        let size = Self::read_string_buf(src)?
+            .parse::<i64>()
+            .map_err(|_| ParseError::ParseIntError)?;
+
+        let count = if size < 0 { 0 } else { size as usize * multiplier };
+
+        // Do not pre-size from the untrusted header length: a crafted reply like
+        // `%9000000000000000000\r\n` would otherwise panic with "capacity
+        // overflow" (or reserve tens of GB). The Vec grows as elements are
+        // actually read, so each one is validated against the available bytes.
+        let mut items: Vec<RespType> = Vec::new();
+        for _ in 0..count {
+            items.push(Self::from_buf(src)?);
+        }
+
+        Ok(items)
+    }
+
+    fn read_line_buf(src: &mut Bytes) -> Result<Bytes, ParseError> {
+        if let Some(position) = src.windows(2).position(|window| window == b"\r\n") {
+            let line = src.split_to(position);
+            src.advance(2);
+            Ok(line)
+        } else {
+            Err(ParseError::UnexpectedEof)
         }
     }
 
@@ -104,13 +529,73 @@ impl RespType {
             return Err(ParseError::UnexpectedEof);
         }
 
-        let bulk_data = remaining[..size as usize].to_vec();
+        let bulk_data = Bytes::copy_from_slice(&remaining[..size as usize]);
         let remaining = &remaining[end_idx..];
 
         Ok((remaining, RespType::BulkString(Some(bulk_data))))
     }
 
-    fn read_array(bytes: &[u8]) -> Result<(&[u8], RespType), ParseError> {
+    fn read_bulk_error(bytes: &[u8]) -> Result<(&[u8], RespType), ParseError> {
+        let (remaining, line) = Self::read_line(bytes)?;
+
+        let size = String::from_utf8(line)
+            .map_err(|_| ParseError::FromUtf8Error)?
+            .parse::<usize>()
+            .map_err(|_| ParseError::ParseIntError)?;
+
+        let end_idx = size + 2;
+        if remaining.len() < end_idx {
+            return Err(ParseError::UnexpectedEof);
+        }
+
+        let error = String::from_utf8(remaining[..size].to_vec())
+            .map_err(|_| ParseError::FromUtf8Error)?;
+
+        Ok((&remaining[end_idx..], RespType::BulkError(error)))
+    }
+
+    fn read_verbatim_string(bytes: &[u8]) -> Result<(&[u8], RespType), ParseError> {
+        let (remaining, line) = Self::read_line(bytes)?;
+
+        let size = String::from_utf8(line)
+            .map_err(|_| ParseError::FromUtf8Error)?
+            .parse::<usize>()
+            .map_err(|_| ParseError::ParseIntError)?;
+
+        let end_idx = size + 2;
+        if remaining.len() < end_idx {
+            return Err(ParseError::UnexpectedEof);
+        }
+
+        // The payload is a 3-char format, a ':' and then the data.
+        let payload = &remaining[..size];
+        if payload.len() < 4 || payload[3] != b':' {
+            return Err(ParseError::UnforeseenError);
+        }
+        let format = String::from_utf8(payload[..3].to_vec())
+            .map_err(|_| ParseError::FromUtf8Error)?;
+        let data = Bytes::copy_from_slice(&payload[4..]);
+
+        Ok((
+            &remaining[end_idx..],
+            RespType::VerbatimString { format, data },
+        ))
+    }
+
+    fn read_map(bytes: &[u8]) -> Result<(&[u8], RespType), ParseError> {
+        // A map header `%<n>` is followed by 2n elements (n key/value pairs).
+        let (bytes, items) = Self::read_aggregate(bytes, 2)?;
+
+        let mut map: Vec<(RespType, RespType)> = Vec::with_capacity(items.len() / 2);
+        let mut items = items.into_iter();
+        while let (Some(key), Some(value)) = (items.next(), items.next()) {
+            map.push((key, value));
+        }
+
+        Ok((bytes, RespType::Map(map)))
+    }
+
+    fn read_aggregate(bytes: &[u8], multiplier: usize) -> Result<(&[u8], Vec<RespType>), ParseError> {
         let (mut bytes, line) = Self::read_line(bytes)?;
 
         let size = String::from_utf8(line)
@@ -118,14 +603,16 @@ impl RespType {
             .parse::<i64>()
             .map_err(|_| ParseError::ParseIntError)?;
 
+        let count = if size < 0 { 0 } else { size as usize * multiplier };
+
         let mut items: Vec<RespType> = Vec::new();
-        for _ in 0..size {
-            let (remaining, resp) = Self::from_bytes(&bytes)?;
+        for _ in 0..count {
+            let (remaining, resp) = Self::from_bytes(bytes)?;
             bytes = remaining;
             items.push(resp);
         }
 
-        Ok((bytes, RespType::Array(items)))
+        Ok((bytes, items))
     }
 
     fn read_line(bytes: &[u8]) -> Result<(&[u8], Vec<u8>), ParseError> {
@@ -137,6 +624,341 @@ impl RespType {
             Err(ParseError::UnexpectedEof)
         }
     }
+
+    /// A lower bound on how many more bytes must arrive before the frame at the
+    /// front of `bytes` is complete, or `0` if a whole frame is already present.
+    fn frame_shortfall(bytes: &[u8]) -> usize {
+        match Self::measure(bytes) {
+            Ok(_) => 0,
+            Err(needed) => needed,
+        }
+    }
+
+    /// Measure the frame at the front of `bytes` without allocating.
+    ///
+    /// Returns `Ok(len)` with the byte length of a complete frame, or
+    /// `Err(needed)` with the shortfall — the number of extra bytes required
+    /// before a frame can be parsed. For a bulk string whose header has been
+    /// read this is exactly `len + 2 - available`; aggregates recurse and
+    /// propagate the shortfall of their first incomplete element.
+    fn measure(bytes: &[u8]) -> Result<usize, usize> {
+        let prefix = *bytes.first().ok_or(1usize)?;
+        let body = &bytes[1..];
+        match prefix {
+            b'+' | b'-' | b':' | b',' | b'(' | b'#' | b'_' => Ok(1 + Self::measure_line(body)?),
+            b'$' | b'!' | b'=' => {
+                let line = Self::measure_line(body)?;
+                match Self::parse_len(&body[..line - 2]) {
+                    Some(len) if len >= 0 => {
+                        let available = body.len() - line;
+                        let payload = len as usize + 2;
+                        if available < payload {
+                            Err(payload - available)
+                        } else {
+                            Ok(1 + line + payload)
+                        }
+                    }
+                    // A null ($-1) or malformed length carries no payload; let
+                    // `from_bytes` be the one to accept or reject it.
+                    _ => Ok(1 + line),
+                }
+            }
+            b'*' | b'~' | b'>' => Self::measure_aggregate(body, 1),
+            b'%' => Self::measure_aggregate(body, 2),
+            // Unknown prefix: treat the frame as complete so `from_bytes` reports
+            // the `UnexpectedByte` rather than us stalling for more data.
+            _ => Ok(bytes.len()),
+        }
+    }
+
+    fn measure_aggregate(body: &[u8], multiplier: usize) -> Result<usize, usize> {
+        let line = Self::measure_line(body)?;
+        let count = match Self::parse_len(&body[..line - 2]) {
+            Some(n) if n > 0 => n as usize * multiplier,
+            _ => return Ok(1 + line),
+        };
+
+        let mut consumed = line;
+        for _ in 0..count {
+            consumed += Self::measure(&body[consumed..])?;
+        }
+        Ok(1 + consumed)
+    }
+
+    fn measure_line(bytes: &[u8]) -> Result<usize, usize> {
+        match bytes.windows(2).position(|window| window == b"\r\n") {
+            Some(position) => Ok(position + 2),
+            None => Err(if bytes.last() == Some(&b'\r') { 1 } else { 2 }),
+        }
+    }
+
+    fn parse_len(bytes: &[u8]) -> Option<i64> {
+        std::str::from_utf8(bytes).ok()?.parse::<i64>().ok()
+    }
+}
+
+/// A type that can be appended to a [`Command`] as a single bulk-string argument.
+pub trait Arg {
+    fn into_arg(self) -> Bytes;
+}
+
+impl Arg for &str {
+    fn into_arg(self) -> Bytes {
+        Bytes::copy_from_slice(self.as_bytes())
+    }
+}
+
+impl Arg for i64 {
+    fn into_arg(self) -> Bytes {
+        Bytes::from(self.to_string().into_bytes())
+    }
+}
+
+impl Arg for &[u8] {
+    fn into_arg(self) -> Bytes {
+        Bytes::copy_from_slice(self)
+    }
+}
+
+/// Builder for a command request, e.g. `Command::new("SET").arg("key").arg(42)`.
+///
+/// Collects a command name and its arguments and frames them as the `Array` of
+/// `BulkString`s that a Redis server expects.
+#[derive(Debug, Default, Clone)]
+pub struct Command {
+    args: Vec<Bytes>,
+}
+
+impl Command {
+    pub fn new(name: &str) -> Self {
+        Command {
+            args: vec![Bytes::copy_from_slice(name.as_bytes())],
+        }
+    }
+
+    pub fn arg<A: Arg>(mut self, arg: A) -> Self {
+        self.args.push(arg.into_arg());
+        self
+    }
+
+    /// Frame the accumulated arguments as a RESP `Array` of bulk strings.
+    pub fn build(self) -> RespType {
+        RespType::Array(
+            self.args
+                .into_iter()
+                .map(|arg| RespType::BulkString(Some(arg)))
+                .collect(),
+        )
+    }
+}
+
+impl From<Command> for RespType {
+    fn from(command: Command) -> Self {
+        command.build()
+    }
+}
+
+impl TryFrom<RespType> for String {
+    type Error = RespError;
+
+    fn try_from(value: RespType) -> Result<Self, Self::Error> {
+        value.as_str().map(str::to_string)
+    }
+}
+
+impl TryFrom<RespType> for i64 {
+    type Error = RespError;
+
+    fn try_from(value: RespType) -> Result<Self, Self::Error> {
+        value.as_i64()
+    }
+}
+
+impl TryFrom<RespType> for Vec<u8> {
+    type Error = RespError;
+
+    fn try_from(value: RespType) -> Result<Self, Self::Error> {
+        value.check_error()?;
+        match value {
+            RespType::BulkString(Some(bulk)) => Ok(bulk.to_vec()),
+            _ => Err(RespError::UnexpectedType),
+        }
+    }
+}
+
+impl TryFrom<RespType> for Vec<RespType> {
+    type Error = RespError;
+
+    fn try_from(value: RespType) -> Result<Self, Self::Error> {
+        value.into_vec()
+    }
+}
+
+/// Iterator over the top-level RESP frames in a byte buffer.
+///
+/// Created by [`RespType::iter_frames`]. Each [`Iterator::next`] parses one
+/// frame from the front of the remaining slice; a parse error is surfaced as a
+/// final `Err` item, after which iteration ends.
+#[derive(Debug)]
+pub struct FrameIter<'a> {
+    remaining: &'a [u8],
+}
+
+impl Iterator for FrameIter<'_> {
+    type Item = Result<RespType, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        match RespType::from_bytes(self.remaining) {
+            Ok((remaining, resp)) => {
+                self.remaining = remaining;
+                Some(Ok(resp))
+            }
+            Err(error) => {
+                self.remaining = &[];
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// An incremental decoder for pulling RESP frames off a byte stream.
+///
+/// [`RespType::from_bytes`] collapses a truncated frame and a malformed one
+/// into the same [`ParseError::UnexpectedEof`], so a networked caller cannot
+/// tell "need more data" from "give up". `Decoder` buffers whatever has arrived
+/// and reports [`None`] until a whole frame is present; the caller reads more
+/// bytes, feeds them in, and calls [`Decoder::decode`] again.
+#[derive(Debug, Default)]
+pub struct Decoder {
+    buf: BytesMut,
+}
+
+impl Decoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append freshly read bytes to the internal buffer.
+    pub fn feed(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// A lower bound on how many more bytes must be [`fed`](Decoder::feed)
+    /// before [`decode`](Decoder::decode) can yield a frame, or `0` if a whole
+    /// frame is already buffered. For a bulk string whose header has arrived
+    /// this is exactly `len + 2 - available`, so a caller can size its next
+    /// read instead of looping one chunk at a time.
+    pub fn bytes_needed(&self) -> usize {
+        RespType::frame_shortfall(&self.buf)
+    }
+
+    /// Try to pull exactly one complete frame out of the buffer.
+    ///
+    /// Returns `Ok(None)` when the buffered bytes do not yet contain a whole
+    /// frame, leaving the buffer untouched so the call is idempotent and can be
+    /// retried once more bytes arrive — [`bytes_needed`](Decoder::bytes_needed)
+    /// reports how many more are required. On success the frame's bytes — and
+    /// only those bytes — are consumed, leaving any pipelined tail in place.
+    pub fn decode(&mut self) -> Result<Option<RespType>, ParseError> {
+        match RespType::from_bytes(&self.buf) {
+            Ok((remaining, resp)) => {
+                let consumed = self.buf.len() - remaining.len();
+                self.buf.advance(consumed);
+                Ok(Some(resp))
+            }
+            Err(ParseError::UnexpectedEof) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// A minimal synchronous client over any `Read + Write` transport.
+///
+/// Wraps a stream (typically a [`TcpStream`](std::net::TcpStream)), framing
+/// outgoing commands as a RESP `Array` of bulk strings and reading replies back
+/// through a [`Decoder`] so a reply split across several `read`s is reassembled
+/// transparently.
+pub struct Connection<S> {
+    stream: S,
+    decoder: Decoder,
+}
+
+impl<S: Read + Write> Connection<S> {
+    pub fn new(stream: S) -> Self {
+        Connection {
+            stream,
+            decoder: Decoder::new(),
+        }
+    }
+
+    /// Send a single command and read exactly one reply.
+    ///
+    /// The arguments are encoded as an `Array` of `BulkString`s — the form a
+    /// Redis server expects — flushed, and then a reply is read back, growing
+    /// the internal buffer until a full frame arrives.
+    pub fn command<I>(&mut self, args: I) -> Result<RespType, RespError>
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        self.send(args)?;
+        self.read_reply()
+    }
+
+    /// Send `commands` back-to-back and collect one reply per command, in order.
+    ///
+    /// Replies are correlated positionally: the Nth returned frame answers the
+    /// Nth command sent.
+    pub fn pipeline<C, I>(&mut self, commands: C) -> Result<Vec<RespType>, RespError>
+    where
+        C: IntoIterator<Item = I>,
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        let mut sent = 0;
+        for args in commands {
+            self.send(args)?;
+            sent += 1;
+        }
+
+        let mut replies = Vec::with_capacity(sent);
+        for _ in 0..sent {
+            replies.push(self.read_reply()?);
+        }
+        Ok(replies)
+    }
+
+    fn send<I>(&mut self, args: I) -> Result<(), RespError>
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+    {
+        let array = RespType::Array(
+            args.into_iter()
+                .map(|arg| RespType::BulkString(Some(Bytes::from(arg))))
+                .collect(),
+        );
+        self.stream.write_all(&array.as_bytes())?;
+        self.stream.flush()?;
+        Ok(())
+    }
+
+    fn read_reply(&mut self) -> Result<RespType, RespError> {
+        let mut chunk = [0u8; 4096];
+        loop {
+            // A previous read may already have buffered a whole frame (e.g. the
+            // tail of a pipelined batch), so always try to decode first.
+            if let Some(frame) = self.decoder.decode()? {
+                return Ok(frame);
+            }
+
+            let read = self.stream.read(&mut chunk)?;
+            if read == 0 {
+                return Err(RespError::UnexpectedEof);
+            }
+            self.decoder.feed(&chunk[..read]);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -187,7 +1009,7 @@ mod tests {
         let (_, resp) = RespType::from_bytes(bytes).unwrap();
         assert_eq!(
             resp,
-            RespType::BulkString(Some("foobar".chars().map(|c| c as u8).collect::<Vec<u8>>()))
+            RespType::BulkString(Some(Bytes::from_static(b"foobar")))
         );
     }
 
@@ -256,7 +1078,7 @@ mod tests {
             RespType::Array(vec![
                 RespType::SimpleString("foo".to_string()),
                 RespType::Integer(1000),
-                RespType::BulkString(Some("foobar".chars().map(|c| c as u8).collect::<Vec<u8>>()))
+                RespType::BulkString(Some(Bytes::from_static(b"foobar")))
             ])
         );
     }
@@ -312,4 +1134,393 @@ mod tests {
         let bytes = resp.as_bytes();
         assert_eq!(bytes, b"*2\r\n+foo\r\n+bar\r\n");
     }
+
+    #[test]
+    fn test_parse_null() {
+        let bytes = b"_\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(resp, RespType::Null);
+    }
+
+    #[test]
+    fn test_parse_boolean() {
+        let (_, t) = RespType::from_bytes(b"#t\r\n").unwrap();
+        assert_eq!(t, RespType::Boolean(true));
+        let (_, f) = RespType::from_bytes(b"#f\r\n").unwrap();
+        assert_eq!(f, RespType::Boolean(false));
+    }
+
+    #[test]
+    fn test_parse_double() {
+        let (_, resp) = RespType::from_bytes(b",2.5\r\n").unwrap();
+        assert_eq!(resp, RespType::Double(2.5));
+    }
+
+    #[test]
+    fn test_parse_double_inf() {
+        let (_, resp) = RespType::from_bytes(b",inf\r\n").unwrap();
+        assert_eq!(resp, RespType::Double(f64::INFINITY));
+        let (_, resp) = RespType::from_bytes(b",-inf\r\n").unwrap();
+        assert_eq!(resp, RespType::Double(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_parse_malformed_double_reports_float_error() {
+        assert_eq!(
+            RespType::from_bytes(b",notanumber\r\n"),
+            Err(ParseError::ParseFloatError)
+        );
+    }
+
+    #[test]
+    fn test_parse_big_number() {
+        let bytes = b"(3492890328409238509324850943850943825024385\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(
+            resp,
+            RespType::BigNumber("3492890328409238509324850943850943825024385".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_bulk_error() {
+        let bytes = b"!21\r\nSYNTAX invalid syntax\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(
+            resp,
+            RespType::BulkError("SYNTAX invalid syntax".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_verbatim_string() {
+        let bytes = b"=15\r\ntxt:Some string\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(
+            resp,
+            RespType::VerbatimString {
+                format: "txt".to_string(),
+                data: Bytes::from_static(b"Some string"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let bytes = b"%2\r\n+first\r\n:1\r\n+second\r\n:2\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(
+            resp,
+            RespType::Map(vec![
+                (RespType::SimpleString("first".to_string()), RespType::Integer(1)),
+                (RespType::SimpleString("second".to_string()), RespType::Integer(2)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_set() {
+        let bytes = b"~2\r\n+foo\r\n+bar\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(
+            resp,
+            RespType::Set(vec![
+                RespType::SimpleString("foo".to_string()),
+                RespType::SimpleString("bar".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_push() {
+        let bytes = b">2\r\n+foo\r\n+bar\r\n";
+        let (_, resp) = RespType::from_bytes(bytes).unwrap();
+        assert_eq!(
+            resp,
+            RespType::Push(vec![
+                RespType::SimpleString("foo".to_string()),
+                RespType::SimpleString("bar".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_as_bytes_null() {
+        assert_eq!(RespType::Null.as_bytes(), b"_\r\n");
+    }
+
+    #[test]
+    fn test_as_bytes_boolean() {
+        assert_eq!(RespType::Boolean(true).as_bytes(), b"#t\r\n");
+        assert_eq!(RespType::Boolean(false).as_bytes(), b"#f\r\n");
+    }
+
+    #[test]
+    fn test_as_bytes_double() {
+        assert_eq!(RespType::Double(f64::INFINITY).as_bytes(), b",inf\r\n");
+    }
+
+    #[test]
+    fn test_as_bytes_verbatim_string() {
+        let resp = RespType::VerbatimString {
+            format: "txt".to_string(),
+            data: Bytes::from_static(b"Some string"),
+        };
+        assert_eq!(resp.as_bytes(), b"=15\r\ntxt:Some string\r\n");
+    }
+
+    #[test]
+    fn test_as_bytes_map() {
+        let resp = RespType::Map(vec![(
+            RespType::SimpleString("first".to_string()),
+            RespType::Integer(1),
+        )]);
+        assert_eq!(resp.as_bytes(), b"%1\r\n+first\r\n:1\r\n");
+    }
+
+    #[test]
+    fn test_from_buf_bulk_string_shares_allocation() {
+        let mut src = Bytes::from_static(b"$6\r\nfoobar\r\n");
+        let payload_ptr = src[4..].as_ptr();
+        let resp = RespType::from_buf(&mut src).unwrap();
+        assert_eq!(resp, RespType::BulkString(Some(Bytes::from_static(b"foobar"))));
+        // The payload points into the original buffer rather than a fresh copy.
+        match &resp {
+            RespType::BulkString(Some(data)) => assert_eq!(data.as_ptr(), payload_ptr),
+            other => panic!("expected bulk string, got {:?}", other),
+        }
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_from_buf_leaves_trailing_frame() {
+        let mut src = Bytes::from_static(b"+OK\r\n:7\r\n");
+        let first = RespType::from_buf(&mut src).unwrap();
+        assert_eq!(first, RespType::SimpleString("OK".to_string()));
+        let second = RespType::from_buf(&mut src).unwrap();
+        assert_eq!(second, RespType::Integer(7));
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn test_from_buf_huge_aggregate_does_not_panic() {
+        // A corrupt header must not trigger a multi-GB (or overflowing)
+        // allocation; it should fail gracefully on the missing elements.
+        let mut src = Bytes::from_static(b"%9000000000000000000\r\n");
+        assert_eq!(RespType::from_buf(&mut src), Err(ParseError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_from_buf_nested_array() {
+        let mut src = Bytes::from_static(b"*2\r\n+foo\r\n$3\r\nbar\r\n");
+        let resp = RespType::from_buf(&mut src).unwrap();
+        assert_eq!(
+            resp,
+            RespType::Array(vec![
+                RespType::SimpleString("foo".to_string()),
+                RespType::BulkString(Some(Bytes::from_static(b"bar"))),
+            ])
+        );
+    }
+
+    /// A bidirectional in-memory stream: `reads` is what the "server" sends,
+    /// `writes` captures what the client sent.
+    struct MockStream {
+        reads: std::io::Cursor<Vec<u8>>,
+        writes: Vec<u8>,
+    }
+
+    impl MockStream {
+        fn new(reads: &[u8]) -> Self {
+            MockStream {
+                reads: std::io::Cursor::new(reads.to_vec()),
+                writes: Vec::new(),
+            }
+        }
+    }
+
+    impl Read for MockStream {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.reads.read(buf)
+        }
+    }
+
+    impl Write for MockStream {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.writes.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_command_builder_frames_array_of_bulk_strings() {
+        let command = Command::new("SET").arg("key").arg(42).build();
+        assert_eq!(
+            command,
+            RespType::Array(vec![
+                RespType::BulkString(Some(Bytes::from_static(b"SET"))),
+                RespType::BulkString(Some(Bytes::from_static(b"key"))),
+                RespType::BulkString(Some(Bytes::from_static(b"42"))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_command_arg_accepts_bytes() {
+        let command = Command::new("GET").arg(&b"key"[..]).build();
+        assert_eq!(command.as_bytes(), b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n");
+    }
+
+    #[test]
+    fn test_reply_helpers() {
+        assert_eq!(
+            RespType::SimpleString("OK".to_string()).as_str().unwrap(),
+            "OK"
+        );
+        assert_eq!(RespType::Integer(7).as_i64().unwrap(), 7);
+        assert_eq!(
+            RespType::BulkString(Some(Bytes::from_static(b"hi")))
+                .as_bytes_opt()
+                .unwrap(),
+            Some(&b"hi"[..])
+        );
+        assert_eq!(RespType::BulkString(None).as_bytes_opt().unwrap(), None);
+    }
+
+    #[test]
+    fn test_error_frame_folds_into_resp_error() {
+        let reply = RespType::Error("ERR no such key".to_string());
+        match reply.as_str() {
+            Err(RespError::Server(message)) => assert_eq!(message, "ERR no such key"),
+            other => panic!("expected server error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_from_conversions() {
+        let string: String = RespType::SimpleString("PONG".to_string()).try_into().unwrap();
+        assert_eq!(string, "PONG");
+        let number: i64 = RespType::Integer(99).try_into().unwrap();
+        assert_eq!(number, 99);
+        let items: Vec<RespType> =
+            RespType::Array(vec![RespType::Integer(1)]).try_into().unwrap();
+        assert_eq!(items, vec![RespType::Integer(1)]);
+    }
+
+    #[test]
+    fn test_connection_command_encodes_and_reads_reply() {
+        let mut conn = Connection::new(MockStream::new(b"+OK\r\n"));
+        let reply = conn
+            .command(vec![b"SET".to_vec(), b"key".to_vec(), b"value".to_vec()])
+            .unwrap();
+        assert_eq!(reply, RespType::SimpleString("OK".to_string()));
+        assert_eq!(
+            conn.stream.writes,
+            b"*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n"
+        );
+    }
+
+    #[test]
+    fn test_connection_pipeline_correlates_replies() {
+        let mut conn = Connection::new(MockStream::new(b":1\r\n:2\r\n"));
+        let replies = conn
+            .pipeline(vec![
+                vec![b"INCR".to_vec(), b"x".to_vec()],
+                vec![b"INCR".to_vec(), b"x".to_vec()],
+            ])
+            .unwrap();
+        assert_eq!(replies, vec![RespType::Integer(1), RespType::Integer(2)]);
+    }
+
+    #[test]
+    fn test_iter_frames_drains_pipelined_replies() {
+        let bytes = b"+OK\r\n:7\r\n$3\r\nbar\r\n";
+        let frames = RespType::iter_frames(bytes)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            frames,
+            vec![
+                RespType::SimpleString("OK".to_string()),
+                RespType::Integer(7),
+                RespType::BulkString(Some(Bytes::from_static(b"bar"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_frames_stops_at_end() {
+        let mut frames = RespType::iter_frames(b"+OK\r\n");
+        assert_eq!(frames.next(), Some(Ok(RespType::SimpleString("OK".to_string()))));
+        assert_eq!(frames.next(), None);
+    }
+
+    #[test]
+    fn test_iter_frames_surfaces_error() {
+        let mut frames = RespType::iter_frames(b"+OK\r\n?bad\r\n");
+        assert_eq!(frames.next(), Some(Ok(RespType::SimpleString("OK".to_string()))));
+        assert_eq!(frames.next(), Some(Err(ParseError::UnexpectedByte(b'?'))));
+        assert_eq!(frames.next(), None);
+    }
+
+    #[test]
+    fn test_decoder_waits_for_complete_frame() {
+        let mut decoder = Decoder::new();
+        decoder.feed(b"$6\r\nfoo");
+        // The bulk payload is still truncated, so there is nothing to yield yet.
+        assert_eq!(decoder.decode(), Ok(None));
+        // Calling again without new bytes is idempotent.
+        assert_eq!(decoder.decode(), Ok(None));
+        decoder.feed(b"bar\r\n");
+        assert_eq!(
+            decoder.decode(),
+            Ok(Some(RespType::BulkString(Some(Bytes::from_static(b"foobar")))))
+        );
+        assert_eq!(decoder.decode(), Ok(None));
+    }
+
+    #[test]
+    fn test_decoder_reports_bytes_needed() {
+        let mut decoder = Decoder::new();
+        assert_eq!(decoder.bytes_needed(), 1);
+        decoder.feed(b"$6\r\nfoo");
+        // "foobar\r\n" is 8 bytes; only "foo" arrived, so 5 are still missing.
+        assert_eq!(decoder.bytes_needed(), 5);
+        decoder.feed(b"bar\r\n");
+        assert_eq!(decoder.bytes_needed(), 0);
+    }
+
+    #[test]
+    fn test_decoder_consumes_only_one_frame() {
+        let mut decoder = Decoder::new();
+        decoder.feed(b"+OK\r\n:7\r\n");
+        assert_eq!(
+            decoder.decode(),
+            Ok(Some(RespType::SimpleString("OK".to_string())))
+        );
+        assert_eq!(decoder.decode(), Ok(Some(RespType::Integer(7))));
+        assert_eq!(decoder.decode(), Ok(None));
+    }
+
+    #[test]
+    fn test_decoder_surfaces_malformed_frame() {
+        let mut decoder = Decoder::new();
+        decoder.feed(b"?bogus\r\n");
+        assert_eq!(decoder.decode(), Err(ParseError::UnexpectedByte(b'?')));
+    }
+
+    #[test]
+    fn test_encode_matches_as_bytes() {
+        let resp = RespType::Array(vec![
+            RespType::SimpleString("foo".to_string()),
+            RespType::BulkString(Some(Bytes::from_static(b"bar"))),
+            RespType::Integer(42),
+        ]);
+        let mut dst = Vec::new();
+        resp.encode(&mut dst);
+        assert_eq!(dst, resp.as_bytes());
+    }
 }